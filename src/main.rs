@@ -1,13 +1,109 @@
-// The boundary is of the grid defined by having all cells out side of the grid be dead.
+// The cells outside the grid's bounds are dead by default; see `Topology` to make them wrap instead.
 pub struct GameOfLife {
     width: usize,
     height: usize,
     /// grid stores a series of columns of cell-clusters with each cluster storing 62
     /// cells in a row except for the last column where clusters may store less depending
-    /// of the width of the grid. 
-    grid: Box<[CellCluster]>
+    /// of the width of the grid.
+    grid: Box<[CellCluster]>,
+    /// the grid as it was immediately before the most recent `tick`, kept for `undo`/`diff`.
+    previous: Box<[CellCluster]>,
+    /// older generations, oldest first, kept beyond `previous` when a history limit is set.
+    history: std::collections::VecDeque<Box<[CellCluster]>>,
+    /// maximum number of generations `history` may hold; `0` disables the ring buffer.
+    history_limit: usize,
+    /// index of the current generation (ticks minus successful undos); gates `undo` so it
+    /// can't rewind past the start.
+    generation: u64,
+    /// whether `previous` currently holds a real prior generation. Cleared once `undo` has
+    /// rewound one step with no history ring buffer to supply the generation before that.
+    previous_valid: bool,
+    /// bit `k` of `birth` is set if a dead cell with exactly `k` live neighbors comes alive.
+    birth: u16,
+    /// bit `k` of `survival` is set if a live cell with exactly `k` live neighbors stays alive.
+    survival: u16,
+    /// how cells outside the grid's bounds are treated when computing neighbor counts.
+    topology: Topology,
+    /// reused scratch buffers holding a pre-tick snapshot of the first/last column, sized
+    /// `height`, so that x-wrapping doesn't allocate on every call to `tick`.
+    wrap_scratch: (Vec<CellCluster>, Vec<CellCluster>),
 }
 
+/// the boundary condition applied to cells outside the grid when computing `tick`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// everything outside the grid is dead (the default).
+    Dead,
+    /// both axes wrap around, so the grid behaves like the surface of a torus.
+    Torus,
+    /// the left and right edges wrap around; the top and bottom stay dead.
+    CylinderX,
+    /// the top and bottom edges wrap around; the left and right stay dead.
+    CylinderY,
+}
+
+/// default Conway rule: a dead cell is born with 3 neighbors, a live cell survives with 2 or 3.
+const DEFAULT_BIRTH: u16 = 1 << 3;
+const DEFAULT_SURVIVAL: u16 = (1 << 2) | (1 << 3);
+
+/// parses a Life-like rule in `B<digits>/S<digits>` notation (e.g. `B3/S23` for Conway,
+/// `B36/S23` for HighLife) into birth/survival bitmasks indexed by neighbor count.
+pub fn parse_rule(rule: &str) -> Result<(u16, u16), RuleError> {
+    let (b_part, s_part) = rule.trim().split_once('/').ok_or(RuleError::InvalidFormat)?;
+    let b_digits = b_part.trim().strip_prefix(['B', 'b']).ok_or(RuleError::InvalidFormat)?;
+    let s_digits = s_part.trim().strip_prefix(['S', 's']).ok_or(RuleError::InvalidFormat)?;
+
+    let digits_to_mask = |digits: &str| -> Result<u16, RuleError> {
+        let mut mask = 0u16;
+        for c in digits.chars() {
+            let d = c.to_digit(10).ok_or(RuleError::InvalidDigit(c))?;
+            if d > 8 {
+                return Err(RuleError::InvalidDigit(c));
+            }
+            mask |= 1 << d;
+        }
+        Ok(mask)
+    };
+
+    Ok((digits_to_mask(b_digits)?, digits_to_mask(s_digits)?))
+}
+
+/// formats birth/survival bitmasks back into `B<digits>/S<digits>` notation.
+pub fn rule_to_string(birth: u16, survival: u16) -> String {
+    let digits = |mask: u16| -> String {
+        (0..=8).filter(|k| mask & (1 << k) != 0).map(|k| k.to_string()).collect()
+    };
+    format!("B{}/S{}", digits(birth), digits(survival))
+}
+
+/// a connected cluster of live cells (8-connectivity), as reported by `GameOfLife::components`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Component {
+    pub population: usize,
+    pub min_x: usize,
+    pub min_y: usize,
+    pub max_x: usize,
+    pub max_y: usize,
+}
+
+/// errors produced while parsing a `B/S` rule string.
+#[derive(Debug)]
+pub enum RuleError {
+    InvalidFormat,
+    InvalidDigit(char),
+}
+
+impl std::fmt::Display for RuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RuleError::InvalidFormat => write!(f, "malformed rule, expected `B<digits>/S<digits>`"),
+            RuleError::InvalidDigit(c) => write!(f, "'{}' is not a valid neighbor count digit", c),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
 // Each cell cluster stores the state of 62 cells in a row. The most and least
 // signficant bits are used during the tick function to store a copy of the next 
 // and prev cell of the two adjacent clusters. The most and least signficant bits 
@@ -22,41 +118,214 @@ impl GameOfLife {
         GameOfLife {
             width,
             height,
-            grid: vec![0; columns * height].into()
+            grid: vec![0; columns * height].into(),
+            previous: vec![0; columns * height].into(),
+            history: std::collections::VecDeque::new(),
+            history_limit: 0,
+            generation: 0,
+            previous_valid: false,
+            birth: DEFAULT_BIRTH,
+            survival: DEFAULT_SURVIVAL,
+            topology: Topology::Dead,
+            wrap_scratch: (Vec::new(), Vec::new()),
         }
     }
-    
+
+    /// builds a grid governed by a `B<digits>/S<digits>` rule (e.g. `B36/S23` for HighLife)
+    /// instead of the default Conway rule.
+    pub fn with_rule(width: usize, height: usize, rule: &str) -> Result<GameOfLife, RuleError> {
+        let (birth, survival) = parse_rule(rule)?;
+        let mut game = GameOfLife::new(width, height);
+        game.birth = birth;
+        game.survival = survival;
+        Ok(game)
+    }
+
+    /// builds a grid with a boundary topology other than the default all-dead border.
+    pub fn with_topology(width: usize, height: usize, topology: Topology) -> GameOfLife {
+        let mut game = GameOfLife::new(width, height);
+        game.topology = topology;
+        game
+    }
+
+    /// replaces the rule governing future calls to `tick`.
+    pub fn set_rule(&mut self, rule: &str) -> Result<(), RuleError> {
+        let (birth, survival) = parse_rule(rule)?;
+        self.birth = birth;
+        self.survival = survival;
+        Ok(())
+    }
+
+    /// replaces the boundary topology governing future calls to `tick`.
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+    }
+
+    /// the rule currently governing `tick`, in `B<digits>/S<digits>` notation.
+    pub fn rule(&self) -> String {
+        rule_to_string(self.birth, self.survival)
+    }
+
+    /// enables a ring buffer holding up to `limit` additional past generations, beyond the
+    /// single generation `undo`/`diff` always keep around. Pass `0` to disable it (the default).
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history_limit = limit;
+        while self.history.len() > limit {
+            self.history.pop_front();
+        }
+    }
+
+    /// restores the grid to the previous generation, returning `false` if there isn't one.
+    /// Without an enabled history ring buffer only a single step can be undone; a second
+    /// consecutive call returns `false` rather than toggling back to the undone generation.
+    pub fn undo(&mut self) -> bool {
+        if self.generation == 0 || !self.previous_valid {
+            return false;
+        }
+        std::mem::swap(&mut self.grid, &mut self.previous);
+        self.generation -= 1;
+        match self.history.pop_back() {
+            Some(older) => self.previous = older,
+            None => self.previous_valid = false,
+        }
+        true
+    }
+
+    /// yields the `(x, y, now_alive)` cells that flipped between the previous generation
+    /// and the current one. Only meaningful right after `tick`: once `undo` has rewound
+    /// past the last generation `previous` holds, this yields nothing.
+    pub fn diff(&self) -> impl Iterator<Item = (usize, usize, bool)> {
+        if !self.previous_valid {
+            return Vec::new().into_iter();
+        }
+        let height = self.height;
+        let width = self.width;
+        let mut changes = Vec::new();
+        for (index, (&cur, &prev)) in self.grid.iter().zip(self.previous.iter()).enumerate() {
+            let mut flipped = cur ^ prev;
+            if flipped == 0 {
+                continue;
+            }
+            let column = index / height;
+            let y = index % height;
+            while flipped != 0 {
+                let offset = flipped.trailing_zeros() as usize;
+                flipped &= flipped - 1;
+                if offset == 0 || offset > CLUSTER_LEN {
+                    continue; // the temporary adjacent-cluster bits, not real cells
+                }
+                let x = column * CLUSTER_LEN + offset - 1;
+                if x < width {
+                    changes.push((x, y, (cur >> offset) & 0b1 == 1));
+                }
+            }
+        }
+        changes.into_iter()
+    }
+
     /// computes the generation of the grid in place.
     pub fn tick(&mut self) {
-        /// computes the generation of column. Assumes that the most and least significant 
-        /// bits of the clusters store the state of the adjacent cells.
-        fn tick_column(column: &mut [CellCluster]) {
-            fn tick_cluster(cluster: &mut CellCluster, above: CellCluster, below: CellCluster) {
-                let bit_sum = |a, b, c| (a ^ b ^ c, a&b | a&c | b&c);
-                let (ix, iy) = bit_sum(above, *cluster, below);
-                let (ax, ay) = bit_sum(ix << 1, above ^ below, ix >> 1);
-                let (bx, by) = bit_sum(iy << 1, above & below, iy >> 1);
-                *cluster |= ax;              // three (odd_total /w the condition below) 
-                *cluster &= (ay ^ bx) & !by; // two_or_three_mod4 & !more_than_three 
+        if self.history_limit > 0 && self.previous_valid {
+            self.history.push_back(self.previous.clone());
+            if self.history.len() > self.history_limit {
+                self.history.pop_front();
             }
+        }
+        self.previous.copy_from_slice(&self.grid);
+        self.previous_valid = true;
+        self.generation += 1;
+
+        /// computes the generation of column. Assumes that the most and least significant
+        /// bits of the clusters store the state of the adjacent cells. `wrap_y` feeds the
+        /// last row's state as `above` to the first row (and vice versa) instead of 0.
+        fn tick_column(column: &mut [CellCluster], birth: u16, survival: u16, wrap_y: bool) {
+            fn tick_cluster(cluster: &mut CellCluster, above: CellCluster, below: CellCluster, birth: u16, survival: u16) {
+                let half_add = |a: u64, b: u64| (a ^ b, a & b);
+                let full_add = |a: u64, b: u64, c: u64| (a ^ b ^ c, a & b | a & c | b & c);
+
+                // the eight neighbor bit-planes, each shifted so that bit `i` holds the
+                // state of that neighbor of the cell living at bit `i` of `cluster`.
+                let left = |v: u64| v << 1;
+                let right = |v: u64| v >> 1;
+                let n = [
+                    left(above), above, right(above),
+                    left(*cluster), right(*cluster),
+                    left(below), below, right(below),
+                ];
+
+                // carry-save adder tree summing the eight one-bit planes into a 4-bit
+                // count c0..c3 (c0 = least significant).
+                let (s1, k1) = full_add(n[0], n[1], n[2]);
+                let (s2, k2) = full_add(n[3], n[4], n[5]);
+                let (s3, k3) = half_add(n[6], n[7]);
+
+                let (c0, a1) = full_add(s1, s2, s3);
+                let (t1, a2) = full_add(k1, k2, k3);
+                let (c1, a3) = half_add(t1, a1);
+                let (c2, c3) = half_add(a2, a3);
+
+                let mut survive_mask = 0u64;
+                let mut birth_mask = 0u64;
+                for k in 0u32..=8 {
+                    let eq_k = (if k & 1 != 0 { c0 } else { !c0 })
+                        & (if k & 2 != 0 { c1 } else { !c1 })
+                        & (if k & 4 != 0 { c2 } else { !c2 })
+                        & (if k & 8 != 0 { c3 } else { !c3 });
+                    if survival & (1 << k) != 0 {
+                        survive_mask |= eq_k;
+                    }
+                    if birth & (1 << k) != 0 {
+                        birth_mask |= eq_k;
+                    }
+                }
+                *cluster = (survive_mask & *cluster) | (birth_mask & !*cluster);
+            }
+
+            let height = column.len();
+            let first_orig = column[0];
+            let last_orig = column[height - 1];
 
             let mut clusters = column.iter_mut();
             let mut curr = if let Some(c) = clusters.next() {c} else {return;};
-            let mut above = 0;
+            let mut above = if wrap_y { last_orig } else { 0 };
 
             for below in clusters {
                 let tmp = *curr;
-                tick_cluster(&mut curr, above, *below); 
+                tick_cluster(&mut curr, above, *below, birth, survival);
                 above = tmp;
                 curr = below;
             }
-            tick_cluster(&mut curr, above, 0);
+            let below = if wrap_y { first_orig } else { 0 };
+            tick_cluster(&mut curr, above, below, birth, survival);
         }
 
-        let edge_mask = 0x8000_0000_0000_0001;
+        let (birth, survival) = (self.birth, self.survival);
+        let wrap_x = matches!(self.topology, Topology::Torus | Topology::CylinderX);
+        let wrap_y = matches!(self.topology, Topology::Torus | Topology::CylinderY);
+        let left_edge_mask = 0x0000_0000_0000_0001;
+        let right_edge_mask = 0x8000_0000_0000_0000;
+        let edge_mask = left_edge_mask | right_edge_mask;
         //tail_mask is used to zero extra width in the last rowsumn
         let tail_width = (self.width + CLUSTER_LEN - 1)%CLUSTER_LEN + 1;
-        let tail_mask = edge_mask | (!1u64 << tail_width);  
+        let tail_mask = edge_mask | (!1u64 << tail_width);
+
+        // snapshotted into reused scratch buffers so the x-wrap below can read each column's
+        // original, pre-tick state regardless of which column tick_column processes first,
+        // without allocating on every call.
+        let columns_count = self.grid.len() / self.height;
+        let (first_column, last_column) = &mut self.wrap_scratch;
+        if wrap_x && columns_count > 1 {
+            first_column.clear();
+            first_column.extend_from_slice(&self.grid[0..self.height]);
+            last_column.clear();
+            last_column.extend_from_slice(&self.grid[(columns_count - 1) * self.height..columns_count * self.height]);
+        } else {
+            first_column.clear();
+            last_column.clear();
+        }
+        let first_column = &*first_column;
+        let last_column = &*last_column;
+
         let mut columns = self.grid.chunks_exact_mut(self.height);
         let mut prev = columns.next().unwrap();
 
@@ -65,28 +334,53 @@ impl GameOfLife {
         // cells of each column we progress to the next state w/ tick_column.
         if let Some(mut curr) = columns.next() {
             for (first, second) in prev.iter_mut().zip(curr.iter()) {
-                *first ^= ((second << CLUSTER_LEN) ^ *first) & edge_mask; 
+                *first ^= ((second << CLUSTER_LEN) ^ *first) & right_edge_mask;
+            }
+            if wrap_x {
+                for (first, &last_cell) in prev.iter_mut().zip(last_column.iter()) {
+                    let wrapped = (last_cell >> tail_width) & 1;
+                    *first = (*first & !left_edge_mask) | wrapped;
+                }
+            } else {
+                for first in prev.iter_mut() {
+                    *first &= !left_edge_mask;
+                }
             }
 
             for next in columns {
                 for ((left, mid), right) in prev.iter().zip(curr.iter_mut()).zip(next.iter()) {
                     *mid ^= (((left>>CLUSTER_LEN) | (right << CLUSTER_LEN)) ^ *mid) & edge_mask
                 }
-                tick_column(prev);
+                tick_column(prev, birth, survival, wrap_y);
                 prev = curr;
                 curr = next;
             }
 
             for (left, last) in prev.iter().zip(curr.iter_mut()) {
-                *last ^= ((left >> CLUSTER_LEN) ^ *last) & tail_mask; 
+                *last ^= ((left >> CLUSTER_LEN) ^ *last) & tail_mask;
+            }
+            if wrap_x {
+                let carry_bit = 1u64 << (tail_width + 1);
+                for (last, &first_cell) in curr.iter_mut().zip(first_column.iter()) {
+                    let wrapped = (first_cell >> 1) & 1;
+                    *last = (*last & !carry_bit) | (wrapped << (tail_width + 1));
+                }
+            }
+            tick_column(curr, birth, survival, wrap_y);
+        } else if wrap_x {
+            for f in prev.iter_mut() {
+                let orig = *f;
+                let left_bit = (orig >> tail_width) & 1;
+                let right_bit = (orig >> 1) & 1;
+                *f &= !tail_mask;
+                *f |= left_bit | (right_bit << (tail_width + 1));
             }
-            tick_column(curr);
         } else {
             for f in prev.iter_mut() { //Update bounds on the single column
-                *f &= !tail_mask; 
+                *f &= !tail_mask;
             }
         }
-        tick_column(prev);
+        tick_column(prev, birth, survival, wrap_y);
     }
 
     #[inline]
@@ -95,8 +389,222 @@ impl GameOfLife {
         let offset = (x % CLUSTER_LEN) + 1;
         ((self.grid[index] >> offset) & 0b1) == 1
     }
+
+    /// sets the state of a single cell, growing no storage (x/y must be in bounds).
+    pub fn set_alive(&mut self, x: usize, y: usize, alive: bool) {
+        let index = (x / CLUSTER_LEN) * self.height + y;
+        let offset = (x % CLUSTER_LEN) + 1;
+        if alive {
+            self.grid[index] |= 1 << offset;
+        } else {
+            self.grid[index] &= !(1 << offset);
+        }
+    }
+
+    /// flips the state of a single cell.
+    pub fn toggle(&mut self, x: usize, y: usize) {
+        let index = (x / CLUSTER_LEN) * self.height + y;
+        let offset = (x % CLUSTER_LEN) + 1;
+        self.grid[index] ^= 1 << offset;
+    }
+
+    /// labels the distinct 8-connected clusters of live cells via iterative flood fill.
+    pub fn components(&self) -> Vec<Component> {
+        let mut visited = vec![false; self.width * self.height];
+        let mut components = Vec::new();
+        let mut stack = Vec::new();
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if visited[y * self.width + x] || !self.is_alive(x, y) {
+                    continue;
+                }
+                visited[y * self.width + x] = true;
+                stack.push((x, y));
+
+                let mut population = 0;
+                let (mut min_x, mut min_y) = (x, y);
+                let (mut max_x, mut max_y) = (x, y);
+
+                while let Some((cx, cy)) = stack.pop() {
+                    population += 1;
+                    min_x = min_x.min(cx);
+                    min_y = min_y.min(cy);
+                    max_x = max_x.max(cx);
+                    max_y = max_y.max(cy);
+
+                    for dy in -1i64..=1 {
+                        for dx in -1i64..=1 {
+                            if dx == 0 && dy == 0 {
+                                continue;
+                            }
+                            let nx = match cx.checked_add_signed(dx as isize) { Some(v) => v, None => continue };
+                            let ny = match cy.checked_add_signed(dy as isize) { Some(v) => v, None => continue };
+                            if nx >= self.width || ny >= self.height {
+                                continue;
+                            }
+                            let index = ny * self.width + nx;
+                            if visited[index] || !self.is_alive(nx, ny) {
+                                continue;
+                            }
+                            visited[index] = true;
+                            stack.push((nx, ny));
+                        }
+                    }
+                }
+
+                components.push(Component { population, min_x, min_y, max_x, max_y });
+            }
+        }
+
+        components
+    }
+
+    /// parses a pattern in the Run-Length-Encoded Life format (`x = .., y = ..` header
+    /// followed by `b`/`o`/`$`-tagged runs terminated by `!`). `#`-prefixed lines are
+    /// treated as comments and skipped.
+    pub fn from_rle(text: &str) -> Result<GameOfLife, RleError> {
+        let mut lines = text.lines().filter(|line| !line.starts_with('#'));
+        let header = lines.next().ok_or(RleError::MissingHeader)?;
+        let (width, height, rule) = parse_rle_header(header)?;
+        let mut game = GameOfLife::new(width, height);
+        if let Some(rule) = rule {
+            game.set_rule(&rule).map_err(RleError::InvalidRule)?;
+        }
+
+        let mut x = 0;
+        let mut y = 0;
+        let mut count: usize = 0;
+        'outer: for line in lines {
+            for c in line.chars() {
+                if c.is_ascii_whitespace() {
+                    continue;
+                }
+                if c.is_ascii_digit() {
+                    count = count * 10 + (c as usize - '0' as usize);
+                    continue;
+                }
+                let n = if count == 0 { 1 } else { count };
+                count = 0;
+                match c {
+                    'b' => x += n,
+                    'o' => {
+                        for _ in 0..n {
+                            if x < width && y < height {
+                                game.set_alive(x, y, true);
+                            }
+                            x += 1;
+                        }
+                    }
+                    '$' => {
+                        y += n;
+                        x = 0;
+                    }
+                    '!' => break 'outer,
+                    other => return Err(RleError::InvalidToken(other)),
+                }
+            }
+        }
+        Ok(game)
+    }
+
+    /// emits the pattern in Run-Length-Encoded Life format, coalescing runs and
+    /// wrapping the body near 70 columns as is conventional for the format.
+    pub fn to_rle(&self) -> String {
+        let mut body = String::new();
+        let mut col = 0usize;
+        let push_token = |token: &str, body: &mut String, col: &mut usize| {
+            if *col != 0 && *col + token.len() > 70 {
+                body.push('\n');
+                *col = 0;
+            }
+            body.push_str(token);
+            *col += token.len();
+        };
+
+        let mut pending_blank_rows = 0usize;
+        let mut first_row_emitted = false;
+
+        for y in 0..self.height {
+            let mut runs: Vec<(bool, usize)> = Vec::new();
+            for x in 0..self.width {
+                let alive = self.is_alive(x, y);
+                match runs.last_mut() {
+                    Some(last) if last.0 == alive => last.1 += 1,
+                    _ => runs.push((alive, 1)),
+                }
+            }
+            if matches!(runs.last(), Some((false, _))) {
+                runs.pop();
+            }
+
+            if runs.is_empty() {
+                pending_blank_rows += 1;
+                continue;
+            }
+
+            if first_row_emitted || pending_blank_rows > 0 {
+                let n = pending_blank_rows + if first_row_emitted { 1 } else { 0 };
+                let token = if n == 1 { "$".to_string() } else { format!("{}$", n) };
+                push_token(&token, &mut body, &mut col);
+            }
+            pending_blank_rows = 0;
+            first_row_emitted = true;
+
+            for (alive, n) in runs {
+                let tag = if alive { 'o' } else { 'b' };
+                let token = if n == 1 { tag.to_string() } else { format!("{}{}", n, tag) };
+                push_token(&token, &mut body, &mut col);
+            }
+        }
+        push_token("!", &mut body, &mut col);
+
+        format!("x = {}, y = {}, rule = {}\n{}\n", self.width, self.height, self.rule(), body)
+    }
+}
+
+fn parse_rle_header(line: &str) -> Result<(usize, usize, Option<String>), RleError> {
+    let mut width = None;
+    let mut height = None;
+    let mut rule = None;
+    for part in line.split(',') {
+        let part = part.trim();
+        if let Some(rest) = part.strip_prefix('x') {
+            let rest = rest.trim_start().strip_prefix('=').ok_or(RleError::InvalidHeader)?;
+            width = Some(rest.trim().parse().map_err(|_| RleError::InvalidHeader)?);
+        } else if let Some(rest) = part.strip_prefix('y') {
+            let rest = rest.trim_start().strip_prefix('=').ok_or(RleError::InvalidHeader)?;
+            height = Some(rest.trim().parse().map_err(|_| RleError::InvalidHeader)?);
+        } else if let Some(rest) = part.strip_prefix("rule") {
+            let rest = rest.trim_start().strip_prefix('=').ok_or(RleError::InvalidHeader)?;
+            rule = Some(rest.trim().to_string());
+        }
+    }
+    Ok((width.ok_or(RleError::InvalidHeader)?, height.ok_or(RleError::InvalidHeader)?, rule))
 }
 
+/// errors produced while parsing an RLE pattern.
+#[derive(Debug)]
+pub enum RleError {
+    MissingHeader,
+    InvalidHeader,
+    InvalidToken(char),
+    InvalidRule(RuleError),
+}
+
+impl std::fmt::Display for RleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RleError::MissingHeader => write!(f, "missing RLE header line"),
+            RleError::InvalidHeader => write!(f, "malformed RLE header, expected `x = W, y = H`"),
+            RleError::InvalidToken(c) => write!(f, "unexpected character '{}' in RLE body", c),
+            RleError::InvalidRule(e) => write!(f, "invalid rule in RLE header: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RleError {}
+
 impl GameOfLife {
     fn print(&self) {
         //not optmized just for proof of concept